@@ -35,6 +35,16 @@ fn bench_memo_cache64(c: &mut Criterion) {
             }
         })
     });
+
+    let stats = cache.stats();
+    let total = stats.hits + stats.misses;
+    println!(
+        "MemoCache (size: 64) hit ratio: {:.2}% ({} hits, {} misses, {} evictions)",
+        100.0 * stats.hits as f64 / total as f64,
+        stats.hits,
+        stats.misses,
+        stats.evictions,
+    );
 }
 
 criterion_group!(benches, bench_hash_map, bench_memo_cache64,);
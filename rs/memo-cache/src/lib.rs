@@ -1,4 +1,7 @@
 use core::borrow::Borrow;
+use core::marker::PhantomData;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
 
 /// Key equivalence trait, to support `Borrow` types as keys.
 trait Equivalent<K: ?Sized> {
@@ -17,21 +20,74 @@ where
     }
 }
 
+/// A read-only view of a cache slot, as seen by a [`RetentionPolicy`].
+///
+/// This keeps the policy interface decoupled from the cache's internal slot
+/// representation, so that representation can change without breaking custom policies.
+pub trait SlotView {
+    /// Whether the slot currently holds an entry.
+    fn is_occupied(&self) -> bool;
+
+    /// The access tick the slot was last stamped with, or `None` if it's empty.
+    fn tick(&self) -> Option<u64>;
+}
+
+/// A mutable view of a cache slot, for policies that need to stamp an access tick.
+pub trait SlotMut: SlotView {
+    /// Stamp the slot with a new access tick. A no-op on an empty slot.
+    fn set_tick(&mut self, tick: u64);
+}
+
 /// A single key/value slot used in the cache.
 #[derive(Clone, PartialEq)]
 enum KeyValueSlot<K, V> {
-    Used((K, V)),
+    Used { kv: (K, V), tick: u64, weight: u64 },
     Empty,
 }
 
+impl<K, V> SlotView for KeyValueSlot<K, V> {
+    #[inline]
+    fn is_occupied(&self) -> bool {
+        matches!(self, KeyValueSlot::Used { .. })
+    }
+
+    #[inline]
+    fn tick(&self) -> Option<u64> {
+        if let KeyValueSlot::Used { tick, .. } = self {
+            Some(*tick)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, V> SlotMut for KeyValueSlot<K, V> {
+    #[inline]
+    fn set_tick(&mut self, new_tick: u64) {
+        if let KeyValueSlot::Used { tick, .. } = self {
+            *tick = new_tick
+        }
+    }
+}
+
 impl<K, V> KeyValueSlot<K, V> {
+    /// Create a used slot, stamped with the given access tick and weight.
+    #[inline]
+    fn used(k: K, v: V, tick: u64, weight: u64) -> Self {
+        KeyValueSlot::Used {
+            kv: (k, v),
+            tick,
+            weight,
+        }
+    }
+
     /// Check a used slot key for equivalence.
     #[inline]
     fn is_key<Q>(&self, k: &Q) -> bool
     where
         Q: Equivalent<K> + ?Sized,
     {
-        if let KeyValueSlot::Used(kv) = self {
+        if let KeyValueSlot::Used { kv, .. } = self {
             k.equivalent(&kv.0)
         } else {
             false
@@ -41,32 +97,212 @@ impl<K, V> KeyValueSlot<K, V> {
     /// Get the value of a used slot.
     #[inline]
     fn get_value(&self) -> Option<&V> {
-        if let KeyValueSlot::Used(kv) = self {
+        if let KeyValueSlot::Used { kv, .. } = self {
             Some(&kv.1)
         } else {
             None
         }
     }
 
-    /// Update the value of a used slot.
+    /// Get the value of a used slot, mutably.
     #[inline]
-    fn update_value(&mut self, v: V) {
-        if let KeyValueSlot::Used(kv) = self {
-            kv.1 = v
+    fn get_value_mut(&mut self) -> Option<&mut V> {
+        if let KeyValueSlot::Used { kv, .. } = self {
+            Some(&mut kv.1)
+        } else {
+            None
+        }
+    }
+
+    /// Get the weight of a used slot.
+    #[inline]
+    fn weight(&self) -> Option<u64> {
+        if let KeyValueSlot::Used { weight, .. } = self {
+            Some(*weight)
+        } else {
+            None
         }
     }
 }
 
+/// A cache retention policy, deciding which slot is evicted once the cache is full.
+pub trait RetentionPolicy {
+    /// Select the index of the slot to (re)use for a newly inserted key, within a line.
+    fn select_victim<S: SlotView>(line: &[S], cursor: usize) -> usize;
+
+    /// Compute the next insertion cursor, given the current one and the line width.
+    ///
+    /// The default keeps the cursor unchanged, which is correct for policies that don't
+    /// rely on it (e.g. [`Lru`]).
+    #[inline]
+    fn next_cursor(cursor: usize, _ways: usize) -> usize {
+        cursor
+    }
+
+    /// Record an access (a `get` hit or an `insert`) against a used slot.
+    ///
+    /// The default is a no-op, which is correct for policies that don't track recency
+    /// (e.g. [`Fifo`]).
+    #[inline]
+    fn touch<S: SlotMut>(_slot: &mut S, _tick: u64) {}
+
+    /// Select an occupied slot to evict in order to free weight budget, ignoring any
+    /// empty slots. Returns `None` if the line holds no occupied slot.
+    fn select_used_victim<S: SlotView>(line: &[S], cursor: usize) -> Option<usize>;
+}
+
+/// Evicts entries in the order they were inserted (first in, first out).
+///
+/// This is the default policy.
+pub struct Fifo;
+
+impl RetentionPolicy for Fifo {
+    #[inline]
+    fn select_victim<S: SlotView>(line: &[S], cursor: usize) -> usize {
+        line.iter()
+            .position(|s| !s.is_occupied())
+            .or_else(|| Self::select_used_victim(line, cursor))
+            .expect("line must be non-empty")
+    }
+
+    #[inline]
+    fn next_cursor(cursor: usize, ways: usize) -> usize {
+        (cursor + 1) % ways
+    }
+
+    #[inline]
+    fn select_used_victim<S: SlotView>(line: &[S], _cursor: usize) -> Option<usize> {
+        // `Fifo` never re-stamps a slot on access, so each slot keeps the tick it was
+        // inserted with and the smallest tick belongs to the oldest entry.
+        line.iter()
+            .enumerate()
+            .filter(|(_, s)| s.is_occupied())
+            .min_by_key(|(_, s)| s.tick().unwrap_or(u64::MAX))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Evicts the least-recently-used entry; a `get` hit or an `insert` promotes its slot.
+pub struct Lru;
+
+impl RetentionPolicy for Lru {
+    #[inline]
+    fn select_victim<S: SlotView>(line: &[S], _cursor: usize) -> usize {
+        line.iter()
+            .position(|s| !s.is_occupied())
+            .unwrap_or_else(|| {
+                line.iter()
+                    .enumerate()
+                    .min_by_key(|(_, s)| s.tick().unwrap_or(u64::MAX))
+                    .map(|(i, _)| i)
+                    .expect("line must be non-empty")
+            })
+    }
+
+    #[inline]
+    fn touch<S: SlotMut>(slot: &mut S, tick: u64) {
+        slot.set_tick(tick);
+    }
+
+    #[inline]
+    fn select_used_victim<S: SlotView>(line: &[S], _cursor: usize) -> Option<usize> {
+        line.iter()
+            .enumerate()
+            .filter(|(_, s)| s.is_occupied())
+            .min_by_key(|(_, s)| s.tick().unwrap_or(u64::MAX))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Compile-time check that `SIZE` is evenly divisible into `WAYS`-wide lines.
+struct AssertDivisible<const SIZE: usize, const WAYS: usize>;
+
+impl<const SIZE: usize, const WAYS: usize> AssertDivisible<SIZE, WAYS> {
+    const OK: () = assert!(
+        WAYS > 0 && SIZE.is_multiple_of(WAYS),
+        "SIZE must be a non-zero multiple of WAYS"
+    );
+}
+
+/// Assigns a weight to a key/value pair, used to enforce a cache's weight budget.
+pub trait Weighter<K, V> {
+    /// Return the weight of a key/value pair.
+    fn weight(&self, k: &K, v: &V) -> u64;
+}
+
+/// The default weighter: every entry has a weight of 1, so the weight budget behaves
+/// like a plain slot count.
+#[derive(Default)]
+pub struct UnitWeighter;
+
+impl<K, V> Weighter<K, V> for UnitWeighter {
+    #[inline]
+    fn weight(&self, _k: &K, _v: &V) -> u64 {
+        1
+    }
+}
+
 /// A small, fixed-size, heap-allocated key/value cache with retention management.
-pub struct MemoCache<K, V, const SIZE: usize> {
+///
+/// The buffer is partitioned into `SIZE / WAYS` fixed-width associative lines: a key is
+/// hashed to pick its line, and lookup/insertion only ever scans that line's `WAYS`
+/// slots, bounding both to `O(WAYS)` regardless of `SIZE`. `WAYS` defaults to `SIZE`,
+/// which puts every slot in a single, fully-associative line (the original behavior).
+///
+/// The retention policy `P` decides which slot within a line is evicted once that line
+/// is full; it defaults to [`Fifo`]. Pass [`Lru`] instead for least-recently-used
+/// eviction, e.g. `MemoCache<K, V, SIZE, WAYS, Lru>`.
+///
+/// Entries are also bounded by a weight budget, via a [`Weighter`] `W` (default
+/// [`UnitWeighter`], where every entry costs 1 and the budget equals `SIZE`): `insert`
+/// rejects a single entry whose weight exceeds the *whole* budget, and otherwise evicts
+/// existing entries (in the retention policy's order) until the new one fits. This lets
+/// `insert` account for wildly different value sizes within a single, predictable memory
+/// bound rather than a fixed slot count; see [`MemoCache::with_budget`].
+///
+/// **The budget is global, but eviction is necessarily per-line**: an `insert` can only
+/// evict slots from the new key's own line (the same `O(WAYS)` bound that makes lookup
+/// cheap), not from other lines. So an insert is rejected when evicting every entry of
+/// its own line still doesn't free enough budget for it, even if entries in other lines
+/// hold that weight — those aren't reachable from here.
+pub struct MemoCache<
+    K,
+    V,
+    const SIZE: usize,
+    const WAYS: usize = SIZE,
+    P = Fifo,
+    S = RandomState,
+    W = UnitWeighter,
+> {
     buffer: Vec<KeyValueSlot<K, V>>,
-    cursor: usize,
+    cursor: Vec<usize>,
+    tick: u64,
+    hasher: S,
+    stats: CacheStats,
+    weighter: W,
+    budget: u64,
+    used_weight: u64,
+    _policy: PhantomData<P>,
 }
 
-impl<K, V, const SIZE: usize> MemoCache<K, V, SIZE>
+/// Cache usage counters, returned by [`MemoCache::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups (`get`, `get_mut`, `get_or_insert_with`) that found a cached value.
+    pub hits: u64,
+    /// Number of lookups (`get`, `get_mut`, `get_or_insert_with`) that found no cached value.
+    pub misses: u64,
+    /// Number of occupied slots evicted to make room for a new or updated entry.
+    pub evictions: u64,
+}
+
+impl<K, V, const SIZE: usize, const WAYS: usize, P, S, W> MemoCache<K, V, SIZE, WAYS, P, S, W>
 where
-    K: Clone + Eq,
+    K: Clone + Eq + Hash,
     V: Clone,
+    P: RetentionPolicy,
+    S: BuildHasher + Default,
+    W: Weighter<K, V> + Default,
 {
     /// Create a new cache.
     ///
@@ -79,10 +315,48 @@ where
     /// ```
     #[inline]
     pub fn new() -> Self {
+        Self::with_weighter(W::default(), SIZE as u64)
+    }
+
+    /// Create a new cache with a custom weight budget, using the default weighter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let c = MemoCache::<u32, String, 4>::with_budget(2);
+    /// ```
+    #[inline]
+    pub fn with_budget(budget: u64) -> Self {
+        Self::with_weighter(W::default(), budget)
+    }
+
+    /// Create a new cache with a custom [`Weighter`] and weight budget.
+    ///
+    /// `budget` applies to the cache as a whole (see the struct-level docs for how that
+    /// interacts with per-line eviction).
+    #[inline]
+    pub fn with_weighter(weighter: W, budget: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = AssertDivisible::<SIZE, WAYS>::OK;
+
         let mut buffer = Vec::new();
         buffer.resize(SIZE, KeyValueSlot::Empty);
 
-        Self { buffer, cursor: 0 }
+        let num_lines = SIZE / WAYS;
+
+        Self {
+            buffer,
+            cursor: vec![0; num_lines],
+            tick: 0,
+            hasher: S::default(),
+            stats: CacheStats::default(),
+            weighter,
+            budget,
+            used_weight: 0,
+            _policy: PhantomData,
+        }
     }
 
     /// Get the (fixed) capacity of the cache.
@@ -101,7 +375,59 @@ where
         SIZE
     }
 
-    /// Insert a key/value pair.
+    /// Select the line a key falls into, given its hash.
+    #[inline]
+    fn line_of<Q>(&self, k: &Q) -> usize
+    where
+        Q: Hash + ?Sized,
+    {
+        let num_lines = SIZE / WAYS;
+
+        if num_lines <= 1 {
+            0
+        } else {
+            (self.hasher.hash_one(k) as usize) % num_lines
+        }
+    }
+
+    /// Advance the access tick, renormalizing all stamps first if it's about to overflow.
+    fn bump_tick(&mut self) -> u64 {
+        if self.tick == u64::MAX {
+            self.renormalize_ticks();
+        }
+
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Compact all slot ticks down to small, order-preserving values.
+    ///
+    /// This keeps an extremely long-lived [`Lru`] cache from ever overflowing its tick
+    /// counter, without needing to touch every access.
+    fn renormalize_ticks(&mut self) {
+        let mut indices: Vec<usize> = (0..self.buffer.len())
+            .filter(|&i| self.buffer[i].tick().is_some())
+            .collect();
+
+        indices.sort_by_key(|&i| self.buffer[i].tick().unwrap());
+
+        let mut new_tick = 0;
+        for i in indices {
+            new_tick += 1;
+            self.buffer[i].set_tick(new_tick);
+        }
+
+        self.tick = new_tick;
+    }
+
+    /// Insert a key/value pair, returning whether it was actually stored.
+    ///
+    /// A single element whose weight exceeds the whole budget is rejected outright; one
+    /// that fits evicts other entries in the target line, in the retention policy's
+    /// order, until there's enough budget for it. If the whole line has to be freed and
+    /// still isn't enough (see the struct-level docs on the global-budget/per-line-
+    /// eviction split), the insert is rejected and nothing is stored; an existing entry
+    /// for the key keeps its old value.
     ///
     /// # Examples
     ///
@@ -112,28 +438,134 @@ where
     ///
     /// assert!(c.get(&42).is_none());
     ///
-    /// c.insert(42, "The Answer".to_owned());
+    /// assert!(c.insert(42, "The Answer".to_owned()));
     ///
     /// assert!(c.get(&42).is_some_and(|v| v == "The Answer"));
     /// ```
     #[inline]
-    pub fn insert(&mut self, k: K, v: V) {
-        match self.buffer.iter_mut().find(|e| e.is_key(&k)) {
-            Some(s) => s.update_value(v),
-            None => {
-                *self
-                    .buffer
-                    .get_mut(self.cursor)
-                    .expect("invalid cursor value") = KeyValueSlot::Used((k, v));
+    pub fn insert(&mut self, k: K, v: V) -> bool {
+        let tick = self.bump_tick();
+        let weight = self.weighter.weight(&k, &v);
 
-                // Move the cursor over the buffer elements sequentially, creating FIFO behavior.
-                self.cursor = (self.cursor + 1) % SIZE;
-            }
+        if weight > self.budget {
+            return false;
+        }
+
+        let line_idx = self.line_of(&k);
+        let start = line_idx * WAYS;
+
+        if let Some(local) = self.buffer[start..start + WAYS]
+            .iter()
+            .position(|e| e.is_key(&k))
+        {
+            let idx = start + local;
+            return self.update_existing(line_idx, idx, k, v, tick, weight);
+        }
+
+        self.insert_new(line_idx, k, v, tick, weight).is_some()
+    }
+
+    /// Replace the key/value stored at `idx`, an existing entry in `line_idx`'s line.
+    ///
+    /// If the new weight doesn't fit the remaining budget, other occupied slots in the
+    /// line are evicted first, in the retention policy's order, exactly as a miss-insert
+    /// would (eviction can only draw on `idx`'s own line; see the struct-level docs).
+    ///
+    /// Returns whether the new value was stored. If the line runs out of entries to
+    /// evict before the new weight fits, the existing entry at `idx` is put back
+    /// unchanged and `false` is returned.
+    fn update_existing(&mut self, line_idx: usize, idx: usize, k: K, v: V, tick: u64, weight: u64) -> bool {
+        let start = line_idx * WAYS;
+        let old_weight = self.buffer[idx].weight().unwrap_or(0);
+
+        // Release the slot's own weight and empty it up front, so the eviction loop
+        // below can't pick it as its own victim and a heavier replacement can reclaim
+        // room from its line neighbours.
+        let old = std::mem::replace(&mut self.buffer[idx], KeyValueSlot::Empty);
+        self.used_weight -= old_weight;
+
+        while self.used_weight + weight > self.budget {
+            let line = &self.buffer[start..start + WAYS];
+
+            let Some(local) = P::select_used_victim(line, self.cursor[line_idx]) else {
+                break;
+            };
+
+            let vidx = start + local;
+            self.used_weight -= self.buffer[vidx].weight().unwrap_or(0);
+            self.buffer[vidx] = KeyValueSlot::Empty;
+            self.stats.evictions += 1;
+        }
+
+        if self.used_weight + weight > self.budget {
+            self.buffer[idx] = old;
+            self.used_weight += old_weight;
+            return false;
         }
+
+        // Keep the slot's insertion tick and let the policy decide whether the update
+        // counts as an access (`Lru` re-stamps it, `Fifo` keeps the entry's place).
+        let old_tick = old.tick().unwrap_or(tick);
+        self.buffer[idx] = KeyValueSlot::used(k, v, old_tick, weight);
+        P::touch(&mut self.buffer[idx], tick);
+        self.used_weight += weight;
+        true
+    }
+
+    /// Store a new key/value pair in `line_idx`'s line, evicting occupied slots first (in
+    /// the retention policy's order, and only within that line) until `weight` fits the
+    /// remaining budget.
+    ///
+    /// Returns the absolute buffer index the entry was stored at, or `None` if the whole
+    /// line was freed and it still doesn't fit: either `weight` exceeds `budget` outright
+    /// (which callers are expected to have already checked), or the line's own entries
+    /// don't hold enough weight to free, even though other lines do (see the struct-level
+    /// docs on the global-budget/per-line-eviction split).
+    fn insert_new(&mut self, line_idx: usize, k: K, v: V, tick: u64, weight: u64) -> Option<usize> {
+        let start = line_idx * WAYS;
+
+        // Free weight budget within the target line, evicting in the policy's order,
+        // until the new entry fits (or the whole line has been freed).
+        while self.used_weight + weight > self.budget {
+            let line = &self.buffer[start..start + WAYS];
+
+            let Some(local) = P::select_used_victim(line, self.cursor[line_idx]) else {
+                break;
+            };
+
+            let idx = start + local;
+            self.used_weight -= self.buffer[idx].weight().unwrap_or(0);
+            self.buffer[idx] = KeyValueSlot::Empty;
+            self.stats.evictions += 1;
+        }
+
+        if self.used_weight + weight > self.budget {
+            return None;
+        }
+
+        let line = &self.buffer[start..start + WAYS];
+        let victim = P::select_victim(line, self.cursor[line_idx]);
+        let idx = start + victim;
+
+        if matches!(self.buffer[idx], KeyValueSlot::Used { .. }) {
+            self.used_weight -= self.buffer[idx].weight().unwrap_or(0);
+            self.stats.evictions += 1;
+        }
+
+        self.buffer[idx] = KeyValueSlot::used(k, v, tick, weight);
+        self.used_weight += weight;
+        self.cursor[line_idx] = P::next_cursor(self.cursor[line_idx], WAYS);
+
+        Some(idx)
     }
 
     /// Lookup a cache entry by key.
     ///
+    /// A successful lookup counts as an access for the cache's retention policy (so
+    /// under [`Lru`] this promotes the entry) and updates [`MemoCache::stats`], which is
+    /// why `get` takes `&mut self` even under [`Fifo`]. If you need a shared-read lookup
+    /// that does neither, use [`MemoCache::peek`] instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -148,22 +580,330 @@ where
     /// assert!(c.get(&42).is_some_and(|v| v == "The Answer"));
     /// ```
     #[inline]
-    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    pub fn get<Q>(&mut self, k: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Eq + Hash + ?Sized,
     {
-        self.buffer
+        let tick = self.bump_tick();
+        let start = self.line_of(k) * WAYS;
+        let line = &mut self.buffer[start..start + WAYS];
+
+        match line.iter_mut().find(|e| e.is_key(k)) {
+            Some(s) => {
+                P::touch(s, tick);
+                self.stats.hits += 1;
+                Some(s.get_value().unwrap())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Lookup a cache entry by key without promoting it or affecting [`MemoCache::stats`].
+    ///
+    /// Unlike [`MemoCache::get`], this takes `&self`, so it's the cheaper, shared-read
+    /// option when you don't care about retention-policy promotion or hit/miss counting
+    /// (e.g. inspecting a [`Fifo`] cache, whose retention order it can't affect anyway).
+    /// Under [`Lru`], repeatedly peeking a key will *not* keep it from being evicted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    ///
+    /// assert!(c.peek(&42).is_some_and(|v| v == "The Answer"));
+    /// ```
+    #[inline]
+    pub fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let start = self.line_of(k) * WAYS;
+
+        self.buffer[start..start + WAYS]
             .iter()
             .find(|e| e.is_key(k))
             .map(|e| e.get_value().unwrap())
     }
+
+    /// Get the cached value for `k`, computing and inserting it via `f` on a miss.
+    ///
+    /// This collapses the common "check, compute on miss, insert, return" memoization
+    /// pattern into a single call. Returns `None` if `k` wasn't already cached and the
+    /// freshly computed entry doesn't fit the cache's weight budget (see
+    /// [`MemoCache::with_budget`]); with the default [`UnitWeighter`] and the default
+    /// budget of [`MemoCache::new`] this can't happen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// let v = c.get_or_insert_with(42, || "The Answer".to_owned());
+    ///
+    /// assert_eq!(v, Some(&"The Answer".to_owned()));
+    /// ```
+    #[inline]
+    pub fn get_or_insert_with<F>(&mut self, k: K, f: F) -> Option<&V>
+    where
+        F: FnOnce() -> V,
+    {
+        let tick = self.bump_tick();
+        let line_idx = self.line_of(&k);
+        let start = line_idx * WAYS;
+
+        if let Some(local) = self.buffer[start..start + WAYS]
+            .iter()
+            .position(|e| e.is_key(&k))
+        {
+            let idx = start + local;
+
+            P::touch(&mut self.buffer[idx], tick);
+            self.stats.hits += 1;
+
+            return self.buffer[idx].get_value();
+        }
+
+        self.stats.misses += 1;
+
+        let v = f();
+        let weight = self.weighter.weight(&k, &v);
+
+        if weight > self.budget {
+            return None;
+        }
+
+        let idx = self.insert_new(line_idx, k, v, tick, weight)?;
+
+        self.buffer[idx].get_value()
+    }
+
+    /// Lookup a cache entry by key, returning a mutable reference to its value.
+    ///
+    /// Like [`MemoCache::get`], a successful lookup counts as an access for the cache's
+    /// retention policy.
+    ///
+    /// The slot's recorded weight (see [`MemoCache::with_weighter`]) is NOT recomputed
+    /// from the mutated value, so for a cache using a non-[`UnitWeighter`] weighter,
+    /// mutating the returned reference into a heavier or lighter value will leave
+    /// [`MemoCache::weight`] and [`MemoCache::remaining_weight`] out of sync with the
+    /// cache's actual contents. Prefer [`MemoCache::insert`] when weight accuracy
+    /// matters, since it re-weighs the value on every write.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    ///
+    /// if let Some(v) = c.get_mut(&42) {
+    ///     v.push('!');
+    /// }
+    ///
+    /// assert_eq!(c.get(&42), Some(&"The Answer!".to_owned()));
+    /// ```
+    #[inline]
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let tick = self.bump_tick();
+        let start = self.line_of(k) * WAYS;
+        let line = &mut self.buffer[start..start + WAYS];
+
+        match line.iter_mut().find(|e| e.is_key(k)) {
+            Some(s) => {
+                P::touch(s, tick);
+                self.stats.hits += 1;
+                Some(s.get_value_mut().unwrap())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Remove and return the value stored for `k`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    ///
+    /// assert_eq!(c.remove(&42), Some("The Answer".to_owned()));
+    /// assert!(c.get(&42).is_none());
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let line_idx = self.line_of(k);
+        let start = line_idx * WAYS;
+
+        let local = self.buffer[start..start + WAYS]
+            .iter()
+            .position(|e| e.is_key(k))?;
+
+        let idx = start + local;
+        let slot = std::mem::replace(&mut self.buffer[idx], KeyValueSlot::Empty);
+
+        match slot {
+            KeyValueSlot::Used { kv, weight, .. } => {
+                self.used_weight -= weight;
+                Some(kv.1)
+            }
+            KeyValueSlot::Empty => None,
+        }
+    }
+
+    /// Remove all entries from the cache. The hit/miss/eviction counters are kept; see
+    /// [`MemoCache::reset_stats`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    /// c.clear();
+    ///
+    /// assert!(c.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buffer.fill(KeyValueSlot::Empty);
+        self.cursor.fill(0);
+        self.used_weight = 0;
+    }
+
+    /// Get the number of entries currently stored in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    ///
+    /// assert_eq!(c.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer
+            .iter()
+            .filter(|s| matches!(s, KeyValueSlot::Used { .. }))
+            .count()
+    }
+
+    /// Check whether the cache currently holds no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over all occupied entries, in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    ///
+    /// assert_eq!(c.iter().count(), 1);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.buffer.iter().filter_map(|s| {
+            if let KeyValueSlot::Used { kv, .. } = s {
+                Some((&kv.0, &kv.1))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Get a snapshot of the cache's hit/miss/eviction counters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memo_cache::MemoCache;
+    ///
+    /// let mut c = MemoCache::<u32, String, 4>::new();
+    ///
+    /// c.insert(42, "The Answer".to_owned());
+    /// c.get(&42);
+    /// c.get(&7);
+    ///
+    /// let stats = c.stats();
+    ///
+    /// assert_eq!(stats.hits, 1);
+    /// assert_eq!(stats.misses, 1);
+    /// ```
+    #[inline]
+    pub const fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Reset the cache's hit/miss/eviction counters to zero.
+    #[inline]
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// Get the total weight of all entries currently stored, across all lines.
+    #[inline]
+    pub fn weight(&self) -> u64 {
+        self.used_weight
+    }
+
+    /// Get the remaining weight budget before `insert` has to start evicting.
+    ///
+    /// This is a cache-wide figure, so it can be nonzero even when a particular line is
+    /// already full and unable to admit a new entry without evicting from that same line
+    /// (see the struct-level docs on the global-budget/per-line-eviction split).
+    #[inline]
+    pub fn remaining_weight(&self) -> u64 {
+        self.budget.saturating_sub(self.weight())
+    }
 }
 
-impl<K, V, const SIZE: usize> Default for MemoCache<K, V, SIZE>
+impl<K, V, const SIZE: usize, const WAYS: usize, P, S, W> Default
+    for MemoCache<K, V, SIZE, WAYS, P, S, W>
 where
-    K: Clone + Eq,
+    K: Clone + Eq + Hash,
     V: Clone,
+    P: RetentionPolicy,
+    S: BuildHasher + Default,
+    W: Weighter<K, V> + Default,
 {
     fn default() -> Self {
         Self::new()
@@ -192,22 +932,399 @@ mod tests_internal {
     fn test_cursor_state() {
         let mut c = MemoCache::<i32, i32, 2>::new();
 
-        assert_eq!(c.cursor, 0);
+        assert_eq!(c.cursor[0], 0);
 
         c.insert(1, 2);
 
-        assert_eq!(c.cursor, 1);
+        assert_eq!(c.cursor[0], 1);
 
         c.insert(3, 4);
 
-        assert_eq!(c.cursor, 0);
+        assert_eq!(c.cursor[0], 0);
 
         c.insert(5, 6);
 
-        assert_eq!(c.cursor, 1);
+        assert_eq!(c.cursor[0], 1);
 
         c.insert(7, 8);
 
-        assert_eq!(c.cursor, 0);
+        assert_eq!(c.cursor[0], 0);
+    }
+
+    #[test]
+    fn test_fifo_prefers_empty_slot() {
+        let mut c = MemoCache::<i32, i32, 2>::new();
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+        c.remove(&2);
+
+        // Slot `2` is now free; inserting must reuse it rather than evict `1`.
+        c.insert(3, 30);
+
+        assert_eq!(c.get(&1), Some(&10));
+        assert_eq!(c.get(&3), Some(&30));
+        assert_eq!(c.stats().evictions, 0);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut c = MemoCache::<i32, i32, 2, 2, Lru>::new();
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+
+        // Touch `1`, making `2` the least-recently-used entry.
+        assert_eq!(c.get(&1), Some(&10));
+
+        c.insert(3, 30);
+
+        assert!(c.get(&2).is_none());
+        assert_eq!(c.get(&1), Some(&10));
+        assert_eq!(c.get(&3), Some(&30));
+    }
+
+    #[test]
+    fn test_set_associative_lines() {
+        // A deterministic `BuildHasher` that maps an `i32` key straight to its own value
+        // (via an XOR fold of its bytes), so `0` and `2` land in line 0 and `1` and `3`
+        // land in line 1.
+        #[derive(Default)]
+        struct IdentityHasher(u64);
+
+        impl std::hash::Hasher for IdentityHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &b in bytes {
+                    self.0 ^= b as u64;
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct IdentityBuildHasher;
+
+        impl BuildHasher for IdentityBuildHasher {
+            type Hasher = IdentityHasher;
+
+            fn build_hasher(&self) -> IdentityHasher {
+                IdentityHasher(0)
+            }
+        }
+
+        // Two lines of two ways each; entries only ever compete with others that hash
+        // into the same line, so filling both lines must not evict anything.
+        let mut c = MemoCache::<i32, i32, 4, 2, Fifo, IdentityBuildHasher>::new();
+
+        for i in 0..4 {
+            c.insert(i, i * 10);
+        }
+
+        for i in 0..4 {
+            assert_eq!(c.get(&i), Some(&(i * 10)));
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut c = MemoCache::<i32, i32, 2>::new();
+
+        assert_eq!(c.stats(), CacheStats::default());
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+
+        assert_eq!(c.get(&1), Some(&10));
+        assert_eq!(c.get(&3), None);
+
+        assert_eq!(
+            c.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 0,
+            }
+        );
+
+        // The cache is full, so this overwrites an occupied slot.
+        c.insert(3, 30);
+
+        assert_eq!(c.stats().evictions, 1);
+
+        c.reset_stats();
+
+        assert_eq!(c.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut c = MemoCache::<i32, i32, 2>::new();
+
+        // A miss computes and stores the value, recording a single miss.
+        assert_eq!(c.get_or_insert_with(1, || 10), Some(&10));
+        assert_eq!(c.stats(), CacheStats { hits: 0, misses: 1, evictions: 0 });
+
+        // A hit returns the stored value without calling `f`, recording a single hit.
+        assert_eq!(
+            c.get_or_insert_with(1, || panic!("must not recompute a cached value")),
+            Some(&10)
+        );
+        assert_eq!(c.stats(), CacheStats { hits: 1, misses: 1, evictions: 0 });
+    }
+
+    #[test]
+    fn test_get_or_insert_with_rejects_entry_over_budget() {
+        #[derive(Default)]
+        struct FixedWeight;
+
+        impl Weighter<i32, i32> for FixedWeight {
+            fn weight(&self, _k: &i32, _v: &i32) -> u64 {
+                10
+            }
+        }
+
+        let mut c = MemoCache::<i32, i32, 2, 2, Fifo, RandomState, FixedWeight>::with_weighter(
+            FixedWeight,
+            5,
+        );
+
+        // The computed value's weight (10) exceeds the whole budget (5), so nothing is
+        // stored and `None` is returned instead of panicking.
+        assert_eq!(c.get_or_insert_with(1, || 42), None);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn test_weight_budget() {
+        #[derive(Default)]
+        struct ByteLen;
+
+        impl Weighter<i32, String> for ByteLen {
+            fn weight(&self, _k: &i32, v: &String) -> u64 {
+                v.len() as u64
+            }
+        }
+
+        let mut c = MemoCache::<i32, String, 4, 4, Fifo, RandomState, ByteLen>::with_weighter(
+            ByteLen, 5,
+        );
+
+        assert_eq!(c.remaining_weight(), 5);
+
+        assert!(c.insert(1, "ab".to_owned())); // Weight 2, fits within budget 5.
+        assert!(c.insert(2, "cd".to_owned())); // Weight 2, total weight now 4.
+        assert_eq!(c.weight(), 4);
+        assert_eq!(c.remaining_weight(), 1);
+
+        // Doesn't fit without eviction (would need weight 4 + 3 = 7 > 5); evicts `1`
+        // (the oldest entry) to make room.
+        assert!(c.insert(3, "efg".to_owned()));
+
+        assert!(c.get(&1).is_none());
+        assert_eq!(c.get(&2), Some(&"cd".to_owned()));
+        assert_eq!(c.get(&3), Some(&"efg".to_owned()));
+        assert_eq!(c.weight(), 5);
+
+        // A single entry heavier than the whole budget is rejected outright.
+        assert!(!c.insert(4, "too heavy".to_owned()));
+        assert!(c.get(&4).is_none());
+    }
+
+    #[test]
+    fn test_weight_budget_update_eviction() {
+        #[derive(Default)]
+        struct ByteLen;
+
+        impl Weighter<i32, String> for ByteLen {
+            fn weight(&self, _k: &i32, v: &String) -> u64 {
+                v.len() as u64
+            }
+        }
+
+        let mut c = MemoCache::<i32, String, 4, 4, Fifo, RandomState, ByteLen>::with_weighter(
+            ByteLen, 5,
+        );
+
+        assert!(c.insert(1, "ab".to_owned())); // Weight 2.
+        assert!(c.insert(2, "cd".to_owned())); // Weight 2, total weight now 4.
+        assert_eq!(c.weight(), 4);
+
+        // Updating `1` to a heavier value (weight 4) doesn't fit alongside `2` (4 + 2 =
+        // 6 > 5), so it must evict `2` to make room, not silently overrun the budget.
+        assert!(c.insert(1, "wxyz".to_owned()));
+
+        assert_eq!(c.weight(), 4);
+        assert_eq!(c.remaining_weight(), 1);
+        assert_eq!(c.get(&1), Some(&"wxyz".to_owned()));
+        assert!(c.get(&2).is_none());
+    }
+
+    #[test]
+    fn test_weight_budget_is_global_not_per_line() {
+        // 4 lines of 1 way each. A global budget of 2 must admit a unit-weight entry
+        // even though splitting it per-line (2 / 4) would floor to 0 and reject
+        // everything.
+        let mut c = MemoCache::<i32, i32, 4, 1>::with_budget(2);
+
+        assert!(c.insert(1, 10));
+        assert_eq!(c.weight(), 1);
+        assert_eq!(c.remaining_weight(), 1);
+    }
+
+    #[test]
+    fn test_weight_budget_single_item_fits_whole_budget() {
+        #[derive(Default)]
+        struct FixedWeight;
+
+        impl Weighter<i32, i32> for FixedWeight {
+            fn weight(&self, _k: &i32, _v: &i32) -> u64 {
+                30
+            }
+        }
+
+        // 2 lines of two ways each, global budget 50. A single entry weighing 30 fits
+        // the whole budget, even though it would exceed a naively-split per-line share
+        // of 25.
+        let mut c = MemoCache::<i32, i32, 4, 2, Fifo, RandomState, FixedWeight>::with_weighter(
+            FixedWeight,
+            50,
+        );
+
+        assert!(c.insert(1, 10));
+        assert_eq!(c.weight(), 30);
+    }
+
+    #[test]
+    fn test_weight_budget_eviction_is_still_per_line() {
+        // A deterministic `BuildHasher` that maps an `i32` key straight to its own value,
+        // so keys land in predictable lines.
+        #[derive(Default)]
+        struct IdentityHasher(u64);
+
+        impl std::hash::Hasher for IdentityHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &b in bytes {
+                    self.0 ^= b as u64;
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct IdentityBuildHasher;
+
+        impl BuildHasher for IdentityBuildHasher {
+            type Hasher = IdentityHasher;
+
+            fn build_hasher(&self) -> IdentityHasher {
+                IdentityHasher(0)
+            }
+        }
+
+        // Two lines of one way each, global budget 2 (one unit of weight per line).
+        // Both keys below hash into line 0; the other line, and its share of the global
+        // budget, is never touched, so an insert that fills line 0 must evict there
+        // rather than "borrow" from line 1's untouched budget.
+        let mut c =
+            MemoCache::<i32, i32, 2, 1, Fifo, IdentityBuildHasher>::with_budget(2);
+
+        assert_eq!(c.line_of(&0), c.line_of(&2));
+
+        assert!(c.insert(0, 10));
+        assert!(c.insert(2, 20)); // Same line as `0`; evicts it.
+
+        assert!(c.get(&0).is_none());
+        assert_eq!(c.get(&2), Some(&20));
+        assert_eq!(c.weight(), 1);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut c = MemoCache::<i32, String, 2>::new();
+
+        c.insert(1, "ab".to_owned());
+
+        assert!(c.get_mut(&2).is_none());
+
+        c.get_mut(&1).unwrap().push('c');
+
+        assert_eq!(c.get(&1), Some(&"abc".to_owned()));
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut c = MemoCache::<i32, i32, 2, 2, Lru>::new();
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+
+        // A shared reference suffices: `peek` doesn't need `&mut self`.
+        let shared: &MemoCache<i32, i32, 2, 2, Lru> = &c;
+        assert_eq!(shared.peek(&1), Some(&10));
+        assert_eq!(c.stats(), CacheStats::default());
+
+        // Unlike `get`, peeking `1` doesn't promote it under Lru, so it's still the
+        // least-recently-used entry and gets evicted here.
+        c.insert(3, 30);
+
+        assert!(c.get(&1).is_none());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut c = MemoCache::<i32, i32, 2>::new();
+
+        c.insert(1, 10);
+
+        assert_eq!(c.weight(), 1);
+        assert!(c.remove(&2).is_none());
+        assert_eq!(c.remove(&1), Some(10));
+        assert!(c.get(&1).is_none());
+        assert_eq!(c.weight(), 0);
+
+        // Removing again is a no-op.
+        assert!(c.remove(&1).is_none());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut c = MemoCache::<i32, i32, 2>::new();
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+
+        c.clear();
+
+        assert!(c.is_empty());
+        assert_eq!(c.len(), 0);
+        assert_eq!(c.weight(), 0);
+        assert_eq!(c.cursor[0], 0);
+        assert!(c.get(&1).is_none());
+        assert!(c.get(&2).is_none());
+    }
+
+    #[test]
+    fn test_len_and_iter() {
+        let mut c = MemoCache::<i32, i32, 4>::new();
+
+        assert!(c.is_empty());
+        assert_eq!(c.len(), 0);
+
+        c.insert(1, 10);
+        c.insert(2, 20);
+
+        assert_eq!(c.len(), 2);
+        assert!(!c.is_empty());
+
+        let mut entries: Vec<_> = c.iter().map(|(k, v)| (*k, *v)).collect();
+        entries.sort();
+
+        assert_eq!(entries, vec![(1, 10), (2, 20)]);
     }
 }